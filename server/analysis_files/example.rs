@@ -1,96 +1,717 @@
 use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 // Rust example with ownership, borrowing, and error handling
-#[derive(Debug, Clone)]
-struct Vector3D {
-    x: f64,
-    y: f64,
-    z: f64,
+
+/// Minimal numeric abstraction so `Vector3D` can be generic over `f32`/`f64`
+/// without depending on an external crate like `num-traits`.
+trait Float:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_i32(v: i32) -> Self;
+    fn pi() -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn abs(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn acos(self) -> Self;
+}
+
+impl Float for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+    fn one() -> Self {
+        1.0
+    }
+    fn from_i32(v: i32) -> Self {
+        v as f32
+    }
+    fn pi() -> Self {
+        std::f32::consts::PI
+    }
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+    fn sin(self) -> Self {
+        f32::sin(self)
+    }
+    fn cos(self) -> Self {
+        f32::cos(self)
+    }
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+    fn atan2(self, other: Self) -> Self {
+        f32::atan2(self, other)
+    }
+    fn acos(self) -> Self {
+        f32::acos(self)
+    }
+}
+
+impl Float for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+    fn one() -> Self {
+        1.0
+    }
+    fn from_i32(v: i32) -> Self {
+        v as f64
+    }
+    fn pi() -> Self {
+        std::f64::consts::PI
+    }
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+    fn sin(self) -> Self {
+        f64::sin(self)
+    }
+    fn cos(self) -> Self {
+        f64::cos(self)
+    }
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+    fn atan2(self, other: Self) -> Self {
+        f64::atan2(self, other)
+    }
+    fn acos(self) -> Self {
+        f64::acos(self)
+    }
+}
+
+/// A strongly-typed angle, stored internally in radians. Callers construct
+/// it explicitly via `from_degrees` or `from_radians`, so degrees can't be
+/// accidentally passed where radians are expected (or vice versa).
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Angle<T = f64> {
+    rad: T,
+}
+
+impl<T: Float> Angle<T> {
+    fn from_radians(rad: T) -> Self {
+        Angle { rad }
+    }
+
+    fn from_degrees(deg: T) -> Self {
+        Angle {
+            rad: deg * T::pi() / T::from_i32(180),
+        }
+    }
+
+    fn radians(self) -> T {
+        self.rad
+    }
+
+    fn degrees(self) -> T {
+        self.rad * T::from_i32(180) / T::pi()
+    }
+
+    fn sin_cos(self) -> (T, T) {
+        (self.rad.sin(), self.rad.cos())
+    }
+}
+
+/// Tolerant floating-point comparison, since exact `==` is fragile once
+/// values come out of arithmetic like `normalize` or `dot_product`.
+trait ApproxEq {
+    /// A sensible default tolerance for this type.
+    fn approx_epsilon() -> Self;
+    fn approx_eq(&self, other: &Self) -> bool;
+    fn approx_eq_eps(&self, other: &Self, eps: &Self) -> bool;
+}
+
+impl ApproxEq for f32 {
+    fn approx_epsilon() -> Self {
+        1e-4
+    }
+    fn approx_eq_eps(&self, other: &Self, eps: &Self) -> bool {
+        (self - other).abs() < *eps
+    }
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_eps(other, &Self::approx_epsilon())
+    }
+}
+
+impl ApproxEq for f64 {
+    fn approx_epsilon() -> Self {
+        1e-6
+    }
+    fn approx_eq_eps(&self, other: &Self, eps: &Self) -> bool {
+        (self - other).abs() < *eps
+    }
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_eps(other, &Self::approx_epsilon())
+    }
+}
+
+/// Default unit marker for vectors that don't care about coordinate spaces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct UnknownUnit;
+
+/// A 3D vector tagged with a unit/space marker `U`, so that vectors from
+/// different coordinate spaces (e.g. world vs. local) can't be combined
+/// without an explicit `cast_unit`. Defaults to `UnknownUnit` so existing
+/// callers don't need to name a space.
+///
+/// `Clone`/`Copy`/`PartialEq`/`Debug` are implemented by hand rather than
+/// derived, since a derive would also require `U: Clone` etc., but `U` is
+/// a zero-sized marker that never needs to satisfy those bounds itself.
+struct Vector3D<T = f64, U = UnknownUnit> {
+    x: T,
+    y: T,
+    z: T,
+    _unit: PhantomData<U>,
+}
+
+impl<T: Clone, U> Clone for Vector3D<T, U> {
+    fn clone(&self) -> Self {
+        Vector3D {
+            x: self.x.clone(),
+            y: self.y.clone(),
+            z: self.z.clone(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy, U> Copy for Vector3D<T, U> {}
+
+impl<T: PartialEq, U> PartialEq for Vector3D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
 }
 
-impl Vector3D {
-    fn new(x: f64, y: f64, z: f64) -> Self {
-        Vector3D { x, y, z }
+impl<T: fmt::Debug, U> fmt::Debug for Vector3D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Vector3D")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
     }
-    
-    fn magnitude(&self) -> f64 {
+}
+
+impl<T: Float, U> Vector3D<T, U> {
+    fn new(x: T, y: T, z: T) -> Self {
+        Vector3D {
+            x,
+            y,
+            z,
+            _unit: PhantomData,
+        }
+    }
+
+    fn magnitude(&self) -> T {
         (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
     }
-    
-    fn normalize(&self) -> Result<Vector3D, String> {
+
+    fn dot_product(&self, other: &Vector3D<T, U>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn cross_product(&self, other: &Vector3D<T, U>) -> Vector3D<T, U> {
+        Vector3D::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Reinterprets this vector as belonging to a different unit/space `V`,
+    /// without changing its components. Use when a conversion between
+    /// spaces has already happened (or is known to be a no-op).
+    fn cast_unit<V>(&self) -> Vector3D<T, V> {
+        Vector3D::new(self.x, self.y, self.z)
+    }
+
+    /// Builds an orthonormal basis `(v2, v3)` from `self` (assumed
+    /// normalized), using the numerically stable branch from PBRT: pick
+    /// whichever of x/z or y/z has the larger magnitude to avoid dividing
+    /// by a near-zero length when `self` is nearly axis-aligned.
+    fn coordinate_system(&self) -> (Vector3D<T, U>, Vector3D<T, U>) {
+        let zero = T::zero();
+        let v2 = if self.x.abs() > self.y.abs() {
+            let len = (self.x * self.x + self.z * self.z).sqrt();
+            Vector3D::new(-self.z, zero, self.x) / len
+        } else {
+            let len = (self.y * self.y + self.z * self.z).sqrt();
+            Vector3D::new(zero, self.z, -self.y) / len
+        };
+        let v3 = self.cross_product(&v2);
+        (v2, v3)
+    }
+
+    /// Reflects `self` off a surface with the given `normal`.
+    fn reflect(&self, normal: &Vector3D<T, U>) -> Vector3D<T, U> {
+        let two = T::one() + T::one();
+        *self - *normal * two * self.dot_product(normal)
+    }
+
+    /// Flips `self` so that it points into the same hemisphere as `v`.
+    fn face_forward(&self, v: &Vector3D<T, U>) -> Vector3D<T, U> {
+        if self.dot_product(v) < T::zero() {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    /// Builds a Cartesian vector from spherical coordinates: `theta` is the
+    /// polar angle from the z-axis, `phi` the azimuthal angle around it.
+    fn from_spherical(theta: Angle<T>, phi: Angle<T>, radius: T) -> Vector3D<T, U> {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        Vector3D::new(
+            radius * sin_theta * cos_phi,
+            radius * sin_theta * sin_phi,
+            radius * cos_theta,
+        )
+    }
+
+    /// The polar angle of this direction from the z-axis, assuming `self`
+    /// is normalized.
+    fn spherical_theta(&self) -> Angle<T> {
+        let z = if self.z > T::one() {
+            T::one()
+        } else if self.z < -T::one() {
+            -T::one()
+        } else {
+            self.z
+        };
+        Angle::from_radians(z.acos())
+    }
+
+    /// The azimuthal angle of this direction around the z-axis, normalized
+    /// to `[0, 2*pi)`.
+    fn spherical_phi(&self) -> Angle<T> {
+        let p = self.y.atan2(self.x);
+        let two_pi = T::pi() + T::pi();
+        Angle::from_radians(if p < T::zero() { p + two_pi } else { p })
+    }
+}
+
+impl<T: Float + ApproxEq, U> Vector3D<T, U> {
+    fn normalize(&self) -> Result<Vector3D<T, U>, String> {
         let mag = self.magnitude();
-        if mag == 0.0 {
+        if mag.approx_eq_eps(&T::zero(), &T::approx_epsilon()) {
             Err("Cannot normalize zero vector".to_string())
         } else {
-            Ok(Vector3D {
-                x: self.x / mag,
-                y: self.y / mag,
-                z: self.z / mag,
-            })
+            Ok(Vector3D::new(self.x / mag, self.y / mag, self.z / mag))
         }
     }
-    
-    fn dot_product(&self, other: &Vector3D) -> f64 {
-        self.x * other.x + self.y * other.y + self.z * other.z
+}
+
+impl<T: Float + ApproxEq, U> ApproxEq for Vector3D<T, U> {
+    fn approx_epsilon() -> Self {
+        Vector3D::new(T::approx_epsilon(), T::approx_epsilon(), T::approx_epsilon())
     }
-    
-    fn cross_product(&self, other: &Vector3D) -> Vector3D {
-        Vector3D {
-            x: self.y * other.z - self.z * other.y,
-            y: self.z * other.x - self.x * other.z,
-            z: self.x * other.y - self.y * other.x,
-        }
+
+    fn approx_eq_eps(&self, other: &Self, eps: &Self) -> bool {
+        self.x.approx_eq_eps(&other.x, &eps.x)
+            && self.y.approx_eq_eps(&other.y, &eps.y)
+            && self.z.approx_eq_eps(&other.z, &eps.z)
+    }
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_eps(other, &Self::approx_epsilon())
+    }
+}
+
+impl<T: Float, U> Add for Vector3D<T, U> {
+    type Output = Vector3D<T, U>;
+    fn add(self, other: Vector3D<T, U>) -> Vector3D<T, U> {
+        Vector3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Float, U> Sub for Vector3D<T, U> {
+    type Output = Vector3D<T, U>;
+    fn sub(self, other: Vector3D<T, U>) -> Vector3D<T, U> {
+        Vector3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Float, U> Neg for Vector3D<T, U> {
+    type Output = Vector3D<T, U>;
+    fn neg(self) -> Vector3D<T, U> {
+        Vector3D::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Float, U> Mul<T> for Vector3D<T, U> {
+    type Output = Vector3D<T, U>;
+    fn mul(self, scalar: T) -> Vector3D<T, U> {
+        Vector3D::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl<T: Float, U> Div<T> for Vector3D<T, U> {
+    type Output = Vector3D<T, U>;
+    fn div(self, scalar: T) -> Vector3D<T, U> {
+        Vector3D::new(self.x / scalar, self.y / scalar, self.z / scalar)
     }
 }
 
-impl fmt::Display for Vector3D {
+impl<T: Float, U> AddAssign for Vector3D<T, U> {
+    fn add_assign(&mut self, other: Vector3D<T, U>) {
+        self.x = self.x + other.x;
+        self.y = self.y + other.y;
+        self.z = self.z + other.z;
+    }
+}
+
+impl<T: Float, U> SubAssign for Vector3D<T, U> {
+    fn sub_assign(&mut self, other: Vector3D<T, U>) {
+        self.x = self.x - other.x;
+        self.y = self.y - other.y;
+        self.z = self.z - other.z;
+    }
+}
+
+impl<T: Float, U> MulAssign<T> for Vector3D<T, U> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.x = self.x * scalar;
+        self.y = self.y * scalar;
+        self.z = self.z * scalar;
+    }
+}
+
+impl<T: Float, U> DivAssign<T> for Vector3D<T, U> {
+    fn div_assign(&mut self, scalar: T) {
+        self.x = self.x / scalar;
+        self.y = self.y / scalar;
+        self.z = self.z / scalar;
+    }
+}
+
+impl<T: fmt::Display, U> fmt::Display for Vector3D<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({:.2}, {:.2}, {:.2})", self.x, self.y, self.z)
     }
 }
 
+/// A row-major 4x4 transformation matrix that can scale, translate, and
+/// rotate `Vector3D`s, and compose with other transforms via `then`.
+/// Vectors are treated as row vectors, so applying `self` then `other`
+/// is the matrix product `self * other` (see `then`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Transform3D<T = f64> {
+    m: [[T; 4]; 4],
+}
+
+impl<T: Float> Transform3D<T> {
+    fn identity() -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Transform3D {
+            m: [
+                [one, zero, zero, zero],
+                [zero, one, zero, zero],
+                [zero, zero, one, zero],
+                [zero, zero, zero, one],
+            ],
+        }
+    }
+
+    fn scale(sx: T, sy: T, sz: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Transform3D {
+            m: [
+                [sx, zero, zero, zero],
+                [zero, sy, zero, zero],
+                [zero, zero, sz, zero],
+                [zero, zero, zero, one],
+            ],
+        }
+    }
+
+    fn translation(dx: T, dy: T, dz: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Transform3D {
+            m: [
+                [one, zero, zero, zero],
+                [zero, one, zero, zero],
+                [zero, zero, one, zero],
+                [dx, dy, dz, one],
+            ],
+        }
+    }
+
+    fn rotation_x(theta: Angle<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let (sin, cos) = theta.sin_cos();
+        Transform3D {
+            m: [
+                [one, zero, zero, zero],
+                [zero, cos, sin, zero],
+                [zero, -sin, cos, zero],
+                [zero, zero, zero, one],
+            ],
+        }
+    }
+
+    fn rotation_y(theta: Angle<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let (sin, cos) = theta.sin_cos();
+        Transform3D {
+            m: [
+                [cos, zero, -sin, zero],
+                [zero, one, zero, zero],
+                [sin, zero, cos, zero],
+                [zero, zero, zero, one],
+            ],
+        }
+    }
+
+    fn rotation_z(theta: Angle<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let (sin, cos) = theta.sin_cos();
+        Transform3D {
+            m: [
+                [cos, sin, zero, zero],
+                [-sin, cos, zero, zero],
+                [zero, zero, one, zero],
+                [zero, zero, zero, one],
+            ],
+        }
+    }
+
+    /// Composes `self` with `other` so that applying the result is
+    /// equivalent to applying `self` first, then `other`.
+    // The index form mirrors the textbook row-by-column definition of
+    // matrix multiplication more clearly than an iterator chain would.
+    #[allow(clippy::needless_range_loop)]
+    fn then(&self, other: &Transform3D<T>) -> Transform3D<T> {
+        let mut m = [[T::zero(); 4]; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                let mut sum = T::zero();
+                for k in 0..4 {
+                    sum = sum + self.m[i][k] * other.m[k][j];
+                }
+                m[i][j] = sum;
+            }
+        }
+        Transform3D { m }
+    }
+
+    /// Applies this transform to a direction (w = 0), ignoring translation.
+    fn transform_vector<U>(&self, v: &Vector3D<T, U>) -> Vector3D<T, U> {
+        let (x, y, z, w) = (v.x, v.y, v.z, T::zero());
+        Vector3D::new(
+            x * self.m[0][0] + y * self.m[1][0] + z * self.m[2][0] + w * self.m[3][0],
+            x * self.m[0][1] + y * self.m[1][1] + z * self.m[2][1] + w * self.m[3][1],
+            x * self.m[0][2] + y * self.m[1][2] + z * self.m[2][2] + w * self.m[3][2],
+        )
+    }
+
+    /// Applies this transform to a point (w = 1), perspective-dividing by
+    /// the resulting w component when it isn't 1.
+    fn transform_point<U>(&self, v: &Vector3D<T, U>) -> Vector3D<T, U> {
+        let (x, y, z, w) = (v.x, v.y, v.z, T::one());
+        let rx = x * self.m[0][0] + y * self.m[1][0] + z * self.m[2][0] + w * self.m[3][0];
+        let ry = x * self.m[0][1] + y * self.m[1][1] + z * self.m[2][1] + w * self.m[3][1];
+        let rz = x * self.m[0][2] + y * self.m[1][2] + z * self.m[2][2] + w * self.m[3][2];
+        let rw = x * self.m[0][3] + y * self.m[1][3] + z * self.m[2][3] + w * self.m[3][3];
+        if rw == T::one() {
+            Vector3D::new(rx, ry, rz)
+        } else {
+            Vector3D::new(rx / rw, ry / rw, rz / rw)
+        }
+    }
+}
+
+/// A 16-byte-aligned `f32` vector, motivated by glam's `Vec3A`. With
+/// `--cfg feature="simd"` on x86_64, the components live directly in an
+/// SSE register (`__m128`): `add` is a single SSE add, and `dot_product`
+/// (and so `magnitude`) multiplies the components with an SSE multiply,
+/// then sums the lanes and takes the square root in scalar code; every other
+/// target (or a build without the feature) falls back to the plain
+/// scalar arithmetic below, stored in the same 16-byte-aligned layout.
+/// Either way the public API (`new`, the named constants, `add`,
+/// `dot_product`, `magnitude`, the `x`/`y`/`z` accessors) is identical.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[derive(Debug, Clone, Copy)]
+#[repr(C, align(16))]
+struct Vec3A(std::arch::x86_64::__m128);
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+struct Vec3A {
+    x: f32,
+    y: f32,
+    z: f32,
+    _pad: f32,
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl Vec3A {
+    const ZERO: Vec3A = Vec3A::new(0.0, 0.0, 0.0);
+    const ONE: Vec3A = Vec3A::new(1.0, 1.0, 1.0);
+    const X: Vec3A = Vec3A::new(1.0, 0.0, 0.0);
+    const Y: Vec3A = Vec3A::new(0.0, 1.0, 0.0);
+    const Z: Vec3A = Vec3A::new(0.0, 0.0, 1.0);
+
+    const fn new(x: f32, y: f32, z: f32) -> Self {
+        // `_mm_set_ps` isn't callable from a const fn, so build the
+        // register's bit pattern directly via `transmute` instead.
+        Vec3A(unsafe { std::mem::transmute::<[f32; 4], std::arch::x86_64::__m128>([x, y, z, 0.0]) })
+    }
+
+    fn to_array(self) -> [f32; 4] {
+        use std::arch::x86_64::_mm_storeu_ps;
+        let mut out = [0.0f32; 4];
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), self.0) };
+        out
+    }
+
+    fn x(self) -> f32 {
+        self.to_array()[0]
+    }
+
+    fn y(self) -> f32 {
+        self.to_array()[1]
+    }
+
+    fn z(self) -> f32 {
+        self.to_array()[2]
+    }
+
+    fn add(self, other: Self) -> Self {
+        use std::arch::x86_64::_mm_add_ps;
+        Vec3A(unsafe { _mm_add_ps(self.0, other.0) })
+    }
+
+    fn dot_product(self, other: Self) -> f32 {
+        use std::arch::x86_64::_mm_mul_ps;
+        let products = unsafe { _mm_mul_ps(self.0, other.0) };
+        let [x, y, z, _] = Vec3A(products).to_array();
+        x + y + z
+    }
+
+    fn magnitude(self) -> f32 {
+        self.dot_product(self).sqrt()
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl PartialEq for Vec3A {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl Vec3A {
+    const ZERO: Vec3A = Vec3A::new(0.0, 0.0, 0.0);
+    const ONE: Vec3A = Vec3A::new(1.0, 1.0, 1.0);
+    const X: Vec3A = Vec3A::new(1.0, 0.0, 0.0);
+    const Y: Vec3A = Vec3A::new(0.0, 1.0, 0.0);
+    const Z: Vec3A = Vec3A::new(0.0, 0.0, 1.0);
+
+    const fn new(x: f32, y: f32, z: f32) -> Self {
+        Vec3A { x, y, z, _pad: 0.0 }
+    }
+
+    fn x(self) -> f32 {
+        self.x
+    }
+
+    fn y(self) -> f32 {
+        self.y
+    }
+
+    fn z(self) -> f32 {
+        self.z
+    }
+
+    fn add(self, other: Self) -> Self {
+        Vec3A::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+
+    fn dot_product(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn magnitude(self) -> f32 {
+        self.dot_product(self).sqrt()
+    }
+}
+
+impl From<Vector3D<f32>> for Vec3A {
+    fn from(v: Vector3D<f32>) -> Self {
+        Vec3A::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for Vector3D<f32> {
+    fn from(v: Vec3A) -> Self {
+        Vector3D::new(v.x(), v.y(), v.z())
+    }
+}
+
 // Generic function with lifetime parameters
-fn find_longest_vector<'a>(vectors: &'a [Vector3D]) -> Option<&'a Vector3D> {
+fn find_longest_vector<'a, T: Float, U>(vectors: &'a [Vector3D<T, U>]) -> Option<&'a Vector3D<T, U>> {
+    vectors.iter().max_by(|a, b| a.magnitude().partial_cmp(&b.magnitude()).unwrap())
+}
+
+/// Same scan as `find_longest_vector`, over the SIMD-backed representation.
+/// There's no `Cargo.toml`/`criterion` harness in this tree to host a real
+/// benchmark, so this is exercised with a plain `Instant`-based timing in
+/// `main` instead of a `benches/` suite.
+fn find_longest_vec3a(vectors: &[Vec3A]) -> Option<&Vec3A> {
     vectors.iter().max_by(|a, b| a.magnitude().partial_cmp(&b.magnitude()).unwrap())
 }
 
 // Trait for objects that can be transformed
-trait Transformable {
-    fn scale(&mut self, factor: f64);
-    fn translate(&mut self, offset: &Vector3D);
+trait Transformable<T> {
+    fn scale(&mut self, factor: T);
+    fn translate(&mut self, offset: &Self);
 }
 
-impl Transformable for Vector3D {
-    fn scale(&mut self, factor: f64) {
-        self.x *= factor;
-        self.y *= factor;
-        self.z *= factor;
+impl<T: Float, U> Transformable<T> for Vector3D<T, U> {
+    fn scale(&mut self, factor: T) {
+        *self *= factor;
     }
-    
-    fn translate(&mut self, offset: &Vector3D) {
-        self.x += offset.x;
-        self.y += offset.y;
-        self.z += offset.z;
+
+    fn translate(&mut self, offset: &Vector3D<T, U>) {
+        *self += *offset;
     }
 }
 
 fn main() {
-    let mut vectors = vec![
+    let mut vectors: Vec<Vector3D<f64>> = vec![
         Vector3D::new(1.0, 2.0, 3.0),
         Vector3D::new(4.0, 5.0, 6.0),
         Vector3D::new(0.0, 0.0, 0.0),
     ];
-    
+
     println!("Original vectors:");
     for (i, vec) in vectors.iter().enumerate() {
         println!("Vector {}: {} (magnitude: {:.2})", i, vec, vec.magnitude());
     }
-    
+
     // Find longest vector
     if let Some(longest) = find_longest_vector(&vectors) {
         println!("\nLongest vector: {}", longest);
     }
-    
+
     // Test normalization with error handling
     for vec in &vectors {
         match vec.normalize() {
@@ -98,18 +719,103 @@ fn main() {
             Err(e) => println!("Error normalizing {}: {}", vec, e),
         }
     }
-    
+
+    // A vector too small to normalize reliably is still caught as "zero"
+    let tiny: Vector3D<f64> = Vector3D::new(1e-9, 0.0, 0.0);
+    println!("Normalizing a near-zero vector: {:?}", tiny.normalize());
+    println!("0.0 approx_eq 1e-9? {}", 0.0f64.approx_eq(&1e-9));
+
     // Test vector operations
-    let v1 = &vectors[0];
-    let v2 = &vectors[1];
-    
+    let v1 = vectors[0];
+    let v2 = vectors[1];
+
     println!("\nVector operations:");
-    println!("Dot product: {:.2}", v1.dot_product(v2));
-    println!("Cross product: {}", v1.cross_product(v2));
-    
+    println!("Dot product: {:.2}", v1.dot_product(&v2));
+    println!("Cross product: {}", v1.cross_product(&v2));
+    println!("Sum: {}", v1 + v2);
+    println!("Scaled: {}", v1 * 2.0);
+    println!("Negated: {}", -v1);
+
     // Test transformations
     let offset = Vector3D::new(1.0, 1.0, 1.0);
     vectors[0].scale(2.0);
     vectors[0].translate(&offset);
     println!("Transformed vector 0: {}", vectors[0]);
+
+    // Casting into a different unit/space
+    struct WorldSpace;
+    let world_vector: Vector3D<f64, WorldSpace> = vectors[0].cast_unit();
+    println!("Cast to world space: {}", world_vector);
+
+    // Build an orthonormal basis around a normalized direction
+    let normal: Vector3D<f64> = Vector3D::new(0.0, 1.0, 0.0);
+    let (tangent, bitangent) = normal.coordinate_system();
+    println!("Basis around {}: tangent {}, bitangent {}", normal, tangent, bitangent);
+    println!("Reflected: {}", Vector3D::new(1.0, -1.0, 0.0).reflect(&normal));
+    println!(
+        "Face-forwarded: {}",
+        Vector3D::new(0.0, -1.0, 0.0).face_forward(&normal)
+    );
+
+    // Compose a scale and a translation into a single transform
+    let transform = Transform3D::scale(2.0, 2.0, 2.0).then(&Transform3D::translation(1.0, 0.0, 0.0));
+    let point: Vector3D<f64> = Vector3D::new(1.0, 1.0, 1.0);
+    println!(
+        "Transformed point: {}, transformed direction: {}",
+        transform.transform_point(&point),
+        transform.transform_vector(&point)
+    );
+
+    // Spherical coordinates round-trip through an Angle wrapper
+    let direction: Vector3D<f64> = Vector3D::new(0.0, 1.0, 0.0);
+    let (theta, phi) = (direction.spherical_theta(), direction.spherical_phi());
+    println!(
+        "theta: {:.2} deg, phi: {:.2} deg",
+        theta.degrees(),
+        phi.degrees()
+    );
+    let rebuilt: Vector3D<f64> = Vector3D::from_spherical(theta, phi, 1.0);
+    println!("Rebuilt from spherical: {}", rebuilt);
+
+    let rotated = Transform3D::rotation_z(Angle::from_degrees(90.0)).transform_vector(&point);
+    println!("Rotated 90deg around Z: {}", rotated);
+
+    // The aligned, const-constructible Vec3A representation
+    const UP: Vec3A = Vec3A::new(0.0, 1.0, 0.0);
+    let combined = Vec3A::X.add(UP);
+    println!(
+        "Vec3A: {:?} + {:?} = {:?} (magnitude {:.2})",
+        Vec3A::X,
+        UP,
+        combined,
+        combined.magnitude()
+    );
+    let converted: Vector3D<f32> = combined.into();
+    println!("Converted back to Vector3D: {}", converted);
+
+    // A rough timing comparison over a large slice, mirroring
+    // `find_longest_vector`. This isn't a substitute for a real criterion
+    // benchmark (there's no Cargo.toml in this tree to host one), just a
+    // sanity check that the SIMD path isn't slower in practice.
+    let big_vec3a: Vec<Vec3A> = (0..1_000_000)
+        .map(|i| Vec3A::new(i as f32, (i % 7) as f32, (i % 13) as f32))
+        .collect();
+    let big_vector3d: Vec<Vector3D<f32>> = big_vec3a.iter().map(|v| (*v).into()).collect();
+
+    let start = std::time::Instant::now();
+    let longest_vec3a = find_longest_vec3a(&big_vec3a);
+    let vec3a_elapsed = start.elapsed();
+
+    let start = std::time::Instant::now();
+    let longest_vector3d = find_longest_vector(&big_vector3d);
+    let vector3d_elapsed = start.elapsed();
+
+    println!(
+        "Longest of {} vectors -- Vec3A: {:?} in {:?}, Vector3D<f32>: {:?} in {:?}",
+        big_vec3a.len(),
+        longest_vec3a,
+        vec3a_elapsed,
+        longest_vector3d,
+        vector3d_elapsed
+    );
 }